@@ -0,0 +1,29 @@
+//! Exercises each list from a `#![no_std]` crate. This is compiled (not run) by `cargo test`, and
+//! can be checked against the allocator-only build with
+//! `cargo build --example no_std --no-default-features`.
+
+#![no_std]
+
+extern crate alloc;
+extern crate lists;
+
+use alloc::vec::Vec;
+use lists::prelude::*;
+
+pub fn build_all() -> (DoublyLinkedList<u32>, SinglyLinkedList<u32>, PersistentList<u32>) {
+    let mut doubly = DoublyLinkedList::new();
+    doubly.push_back(2);
+    doubly.push_front(1);
+
+    let mut singly = SinglyLinkedList::new();
+    singly.push(2).push(1);
+
+    let persistent = PersistentList::new().prepend(2).prepend(1);
+    (doubly, singly, persistent)
+}
+
+pub fn sum_all() -> u32 {
+    let (doubly, singly, persistent) = build_all();
+    let elems: Vec<u32> = doubly.into_iter().chain(singly).chain(persistent).collect();
+    elems.iter().sum()
+}
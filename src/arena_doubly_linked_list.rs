@@ -0,0 +1,532 @@
+//! This is a doubly-linked list implementation without any unsafe code: the nodes live in a Vec
+//! arena and link to each other by index rather than by pointer. Popped slots are threaded onto a
+//! free list (via their `next` links) so that they get reused by later pushes.
+
+use alloc::vec::Vec;
+use core::iter::IntoIterator;
+use immutable_linked_list;
+use mutable_doubly_linked_list;
+use {Deque, IntoDoubly, IntoPersistent};
+
+////////////////////////////////////////////////////////////////////////////////
+// List implementation
+
+pub struct ArenaList<T> {
+    nodes: Vec<Node<T>>,
+    head: Link,
+    tail: Link,
+    free: Link,  // Head of the free list of vacant slots.
+    len: usize,
+}
+
+type Link = Option<usize>;
+
+struct Node<T> {
+    elem: Option<T>,  // None iff the slot is on the free list.
+    next: Link,
+    prev: Link,
+}
+
+impl<T> ArenaList<T> {
+    pub fn new() -> Self {
+        ArenaList { nodes: Vec::new(), head: None, tail: None, free: None, len: 0 }
+    }
+
+    // SLOTS
+    fn alloc(&mut self, elem: T, next: Link, prev: Link) -> usize {
+        let node = Node { elem: Some(elem), next, prev };
+        match self.free {
+            Some(index) => {
+                self.free = self.nodes[index].next;
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn release(&mut self, index: usize) -> T {
+        let node = &mut self.nodes[index];
+        node.prev = None;
+        node.next = self.free;
+        self.free = Some(index);
+        node.elem.take().unwrap()
+    }
+
+    // PUSH
+    pub fn push_front(&mut self, elem: T) {
+        let old_head = self.head;
+        let index = self.alloc(elem, old_head, None);
+        match old_head {
+            None => self.tail = Some(index),
+            Some(old_head) => self.nodes[old_head].prev = Some(index)
+        }
+        self.head = Some(index);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let old_tail = self.tail;
+        let index = self.alloc(elem, None, old_tail);
+        match old_tail {
+            None => self.head = Some(index),
+            Some(old_tail) => self.nodes[old_tail].next = Some(index)
+        }
+        self.tail = Some(index);
+        self.len += 1;
+    }
+
+    // POP
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|index| {
+            self.head = self.nodes[index].next;
+            match self.head {
+                None => self.tail = None,
+                Some(new_head) => self.nodes[new_head].prev = None
+            }
+            self.len -= 1;
+            self.release(index)
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|index| {
+            self.tail = self.nodes[index].prev;
+            match self.tail {
+                None => self.head = None,
+                Some(new_tail) => self.nodes[new_tail].next = None
+            }
+            self.len -= 1;
+            self.release(index)
+        })
+    }
+
+    // PEEK
+    pub fn peek_front(&self) -> Option<&T> {
+        self.head.and_then(|index| self.nodes[index].elem.as_ref())
+    }
+
+    pub fn peek_back(&self) -> Option<&T> {
+        self.tail.and_then(|index| self.nodes[index].elem.as_ref())
+    }
+
+    // PEEK MUT
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        let nodes = &mut self.nodes;
+        self.head.and_then(move |index| nodes[index].elem.as_mut())
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        let nodes = &mut self.nodes;
+        self.tail.and_then(move |index| nodes[index].elem.as_mut())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // ITER
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            nodes: &self.nodes,
+            front_link: self.head,
+            back_link: self.tail,
+            remaining: self.len,
+        }
+    }
+
+    // Unlike iter(), this allocates a Vec the size of the whole arena (see IterMut), free slots
+    // included. The arena never shrinks, so this is proportional to the list's peak length rather
+    // than its current one. Empty lists skip the allocation.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let slots = if self.len == 0 {
+            Vec::new()
+        } else {
+            self.nodes.iter_mut().map(|node| Slot {
+                elem: node.elem.as_mut(),
+                next: node.next,
+                prev: node.prev,
+            }).collect()
+        };
+        IterMut {
+            slots,
+            front_link: self.head,
+            back_link: self.tail,
+            remaining: self.len,
+        }
+    }
+}
+
+impl<T> Default for ArenaList<T> {
+    fn default() -> Self {
+        ArenaList::new()
+    }
+}
+
+impl<T> Deque<T> for ArenaList<T> {
+    fn push_front(&mut self, elem: T) {
+        ArenaList::push_front(self, elem);
+    }
+
+    fn push_back(&mut self, elem: T) {
+        ArenaList::push_back(self, elem);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        ArenaList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        ArenaList::pop_back(self)
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.peek_front()
+    }
+
+    fn back(&self) -> Option<&T> {
+        self.peek_back()
+    }
+
+    fn len(&self) -> usize {
+        ArenaList::len(self)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// By-reference Iter
+
+// Both ends stop once `remaining` runs out, i.e. when they are about to cross.
+pub struct Iter<'a, T: 'a> {
+    nodes: &'a [Node<T>],
+    front_link: Link,
+    back_link: Link,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.front_link.and_then(|index| {
+            let node = &self.nodes[index];
+            self.front_link = node.next;
+            self.remaining -= 1;
+            node.elem.as_ref()
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.back_link.and_then(|index| {
+            let node = &self.nodes[index];
+            self.back_link = node.prev;
+            self.remaining -= 1;
+            node.elem.as_ref()
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Mutable by-reference Iter
+
+// Handing out mutable references to arbitrary arena slots can't be done safely by indexing, so the
+// arena is split up front into one mutable reference per slot (plus a copy of its links), each of
+// which is taken at most once. This costs an allocation proportional to the arena size.
+pub struct IterMut<'a, T: 'a> {
+    slots: Vec<Slot<'a, T>>,
+    front_link: Link,
+    back_link: Link,
+    remaining: usize,
+}
+
+struct Slot<'a, T: 'a> {
+    elem: Option<&'a mut T>,
+    next: Link,
+    prev: Link,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.front_link.and_then(|index| {
+            let slot = &mut self.slots[index];
+            self.front_link = slot.next;
+            self.remaining -= 1;
+            slot.elem.take()
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.back_link.and_then(|index| {
+            let slot = &mut self.slots[index];
+            self.back_link = slot.prev;
+            self.remaining -= 1;
+            slot.elem.take()
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// IntoIterator
+
+pub struct ListIntoIterator<T> {
+    list: ArenaList<T>,
+}
+
+impl<T> Iterator for ListIntoIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for ListIntoIterator<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> IntoIterator for ArenaList<T> {
+    type Item = T;
+    type IntoIter = ListIntoIterator<T>;
+
+    fn into_iter(self) -> ListIntoIterator<T> {
+        ListIntoIterator { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArenaList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArenaList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversions to the other lists
+
+impl<T> IntoDoubly<T> for ArenaList<T> {
+    fn into_doubly(self) -> mutable_doubly_linked_list::List<T> {
+        let mut doubly = mutable_doubly_linked_list::List::new();
+        for elem in self {
+            doubly.push_back(elem);
+        }
+        doubly
+    }
+}
+
+impl<T> IntoPersistent<T> for ArenaList<T> {
+    fn into_persistent(self) -> immutable_linked_list::List<T> {
+        immutable_linked_list::List::from_double_ended(self)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::ArenaList;
+    use std::prelude::v1::*;
+
+    #[test]
+    fn test_push_pop_front() {
+        let mut list = ArenaList::new();
+        list.push_front(1);
+        assert_eq!(Some(&1), list.peek_front());
+        list.push_front(2);
+        assert_eq!(Some(&2), list.peek_front());
+        list.push_front(3);
+        assert_eq!(Some(&3), list.peek_front());
+        assert_eq!(Some(3), list.pop_front());
+        assert_eq!(Some(2), list.pop_front());
+        assert_eq!(Some(1), list.pop_front());
+        assert_eq!(None, list.pop_front());
+    }
+
+    #[test]
+    fn test_push_pop_back() {
+        let mut list = ArenaList::new();
+        list.push_back(1);
+        assert_eq!(Some(&1), list.peek_back());
+        list.push_back(2);
+        assert_eq!(Some(&2), list.peek_back());
+        list.push_back(3);
+        assert_eq!(Some(&3), list.peek_back());
+        assert_eq!(Some(3), list.pop_back());
+        assert_eq!(Some(2), list.pop_back());
+        assert_eq!(Some(1), list.pop_back());
+        assert_eq!(None, list.pop_back());
+    }
+
+    #[test]
+    fn test_push_pop_both() {
+        let mut list = ArenaList::new();
+        list.push_back(2);
+        assert_eq!(Some(&2), list.peek_back());
+        list.push_front(1);
+        assert_eq!(Some(&1), list.peek_front());
+        assert_eq!(Some(&2), list.peek_back());
+        list.push_back(3);
+        assert_eq!(Some(&1), list.peek_front());
+        assert_eq!(Some(&3), list.peek_back());
+        assert_eq!(3, list.len());
+        assert_eq!(Some(3), list.pop_back());
+        assert_eq!(Some(1), list.pop_front());
+        assert_eq!(Some(2), list.pop_front());
+        assert_eq!(None, list.pop_front());
+        assert_eq!(None, list.pop_back());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut list = ArenaList::new();
+        assert_eq!(None, list.peek_front_mut());
+        list.push_back(1);
+        list.push_back(2);
+        *list.peek_front_mut().unwrap() = 10;
+        *list.peek_back_mut().unwrap() = 20;
+        assert_eq!(vec![10, 20], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_slot_reuse() {
+        let mut list = ArenaList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(Some(1), list.pop_front());
+        assert_eq!(Some(2), list.pop_front());
+        list.push_front(5);
+        list.push_back(6);
+        assert_eq!(3, list.nodes.len());
+        list.push_back(7);
+        assert_eq!(4, list.nodes.len());
+        assert_eq!(vec![5, 3, 6, 7], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list = ArenaList::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        let mut i = 3;
+        for val in list {
+            assert_eq!(i, val);
+            i -= 1;
+        }
+
+        let mut list = ArenaList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        let mut iter = list.into_iter();
+        assert_eq!(Some(1), iter.next());
+        assert_eq!(Some(3), iter.next_back());
+        assert_eq!(Some(2), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list = ArenaList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        let mut iter = list.iter();
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(Some(&3), iter.next_back());
+        assert_eq!(Some(&2), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+
+        let mut iter = list.iter();
+        assert_eq!(Some(&3), iter.next_back());
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(Some(&2), iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+
+        for (i, val) in (1..).zip(&list) {
+            assert_eq!(i, *val);
+        }
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list = ArenaList::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        let mut i = 3;
+        for val in list.iter_mut() {
+            assert_eq!(i, *val);
+            *val = 3 - i;
+            i -= 1;
+        }
+        for (i, val) in (0..).zip(&mut list) {
+            assert_eq!(i, *val);
+            *val = 3 - i;
+        }
+        let mut i = 3;
+        for val in &list {
+            assert_eq!(i, *val);
+            i -= 1;
+        }
+
+        let mut iter = list.iter_mut();
+        assert_eq!(Some(&mut 1), iter.next_back());
+        assert_eq!(Some(&mut 3), iter.next());
+        assert_eq!(Some(&mut 2), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn test_iter_mut_empty() {
+        let mut list = ArenaList::new();
+        list.push_back(1);
+        list.pop_back();
+        let iter = list.iter_mut();
+        assert_eq!(0, iter.slots.capacity());
+        assert_eq!(0, iter.count());
+    }
+}
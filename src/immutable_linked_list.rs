@@ -1,5 +1,17 @@
-use std::iter::IntoIterator;
-use std::rc::Rc;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cmp::{self, Ordering};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, IntoIterator, Zip};
+use mutable_doubly_linked_list;
+use mutable_linked_list;
+use {IntoDoubly, Stack};
+
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, Serializer};
 
 ////////////////////////////////////////////////////////////////////////////////
 // List implementation
@@ -12,6 +24,7 @@ type Link<T> = Option<Rc<Node<T>>>;
 
 struct Node<T> {
     elem: T,
+    len: usize,  // Length of the list starting at this node. Always valid since nodes are immutable.
     next: Link<T>,
 }
 
@@ -21,11 +34,24 @@ impl<T> List<T> {
     }
 
     pub fn prepend(&self, elem: T) -> List<T> {
-        List { head: Some(Rc::new(Node { elem: elem, next: self.head.clone() })) }
+        List { head: Some(Rc::new(Node { elem, len: self.len() + 1, next: self.head.clone() })) }
     }
 
     pub fn cons(elem: T, list: List<T>) -> List<T> { cons(elem, list) }
 
+    // Iterates in the same order as the slice.
+    pub fn from_slice(slice: &[T]) -> List<T> where T: Clone {
+        List::from_double_ended(slice.iter().cloned())
+    }
+
+    // Iterates in the same order as `iter`. Drained from the back, which builds the list in order
+    // without buffering.
+    pub fn from_double_ended<I>(iter: I) -> List<T>
+        where I: IntoIterator<Item = T>, I::IntoIter: DoubleEndedIterator
+    {
+        iter.into_iter().rev().fold(List::new(), |list, elem| list.prepend(elem))
+    }
+
     pub fn tail(&self) -> List<T> {
         List { head: self.head.as_ref().and_then(|node_ref| node_ref.next.clone()) }
     }
@@ -34,15 +60,229 @@ impl<T> List<T> {
         self.head.as_ref().map(|node_ref| &node_ref.elem)
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn head_tail(&self) -> Option<(&T, List<T>)> {
+        self.head.as_ref().map(|node_ref| (&node_ref.elem, List { head: node_ref.next.clone() }))
+    }
+
+    // Moves the head element out if this list is the head node's sole owner. Otherwise (including
+    // when empty), hands back the untouched list.
+    pub fn try_pop(mut self) -> Result<(T, List<T>), List<T>> {
+        match self.head.take() {
+            None => Err(self),
+            Some(node_rc) => match Rc::try_unwrap(node_rc) {
+                Ok(node) => Ok((node.elem, List { head: node.next })),
+                Err(node_rc) => {
+                    self.head = Some(node_rc);
+                    Err(self)
+                }
+            },
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    // The sub-list starting at index n, sharing structure with this list. Empty past the end.
+    pub fn nth_tail(&self, n: usize) -> List<T> {
+        let mut link = &self.head;
+        for _ in 0..n {
+            match *link {
+                Some(ref node) => link = &node.next,
+                None => break,
+            }
+        }
+        List { head: link.clone() }
+    }
+
+    // The first n elements. These are copied since they precede a potentially shared tail, unless
+    // the whole list is requested.
+    pub fn take(&self, n: usize) -> List<T> where T: Clone {
+        if n >= self.len() {
+            return self.clone();
+        }
+        self.iter().take(n).cloned().collect()
+    }
+
+    // Complements take(); same as nth_tail().
+    pub fn drop_n(&self, n: usize) -> List<T> {
+        self.nth_tail(n)
+    }
+
+    // The copied prefix of length n and the shared suffix starting at index n.
+    pub fn split_at(&self, n: usize) -> (List<T>, List<T>) where T: Clone {
+        (self.take(n), self.drop_n(n))
+    }
+
+    // O(1) check for whether both lists are the same shared structure (or are both empty).
+    pub fn shares_with(&self, other: &List<T>) -> bool {
+        match (self.head.as_ref(), other.head.as_ref()) {
+            (Some(node1), Some(node2)) => Rc::ptr_eq(node1, node2),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn head_strong_count(&self) -> usize {
+        self.head.as_ref().map_or(0, Rc::strong_count)
+    }
+
+    // Number of trailing nodes physically shared by both lists. Since shared suffixes have equal
+    // lengths, first skip ahead in the longer list, then walk both in lockstep until they meet.
+    pub fn shared_suffix_len(&self, other: &List<T>) -> usize {
+        let len = cmp::min(self.len(), other.len());
+        let mut list1 = self.nth_tail(self.len() - len);
+        let mut list2 = other.nth_tail(other.len() - len);
+        while !list1.shares_with(&list2) {
+            list1 = list1.tail();
+            list2 = list2.tail();
+        }
+        list1.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.head.as_ref().map_or(0, |node_ref| node_ref.len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter { link: &self.head }
     }
+
+    // Yields this list followed by each of its non-empty tails, each sharing structure with self.
+    pub fn iter_rc(&self) -> IterRc<'_, T> {
+        IterRc { link: &self.head }
+    }
+
+    // Pairs up elements until the shorter list is exhausted.
+    pub fn zip<'a, U>(&'a self, other: &'a List<U>) -> Zip<Iter<'a, T>, Iter<'a, U>> {
+        self.iter().zip(other.iter())
+    }
+
+    pub fn contains(&self, x: &T) -> bool where T: PartialEq {
+        self.iter().any(|elem| elem == x)
+    }
+
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.iter().cloned().collect()
+    }
+
+    // Always clones, since every node of the clone is shared with self. The consuming
+    // into_doubly() moves elements out of uniquely-owned nodes instead.
+    pub fn to_doubly(&self) -> mutable_doubly_linked_list::List<T> where T: Clone {
+        self.clone().into_doubly()
+    }
+
+    pub fn reverse(&self) -> List<T> where T: Clone {
+        self.iter().fold(List::new(), |list, elem| list.prepend(elem.clone()))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> List<U> {
+        self.iter().map(f).collect()
+    }
+
+    // The kept elements are copied since removing interior elements precludes sharing.
+    pub fn filter<F: FnMut(&T) -> bool>(&self, mut pred: F) -> List<T> where T: Clone {
+        self.iter().filter(|elem| pred(elem)).cloned().collect()
+    }
+
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    // Right fold. Rather than recursing to the end of the list (which would overflow the stack on
+    // long lists), the element references are first buffered and then folded in reverse.
+    pub fn foldr<B, F: FnMut(&T, B) -> B>(&self, init: B, mut f: F) -> B {
+        let elems: Vec<&T> = self.iter().collect();
+        elems.into_iter().rev().fold(init, |acc, elem| f(elem, acc))
+    }
+
+    // Advances the list by one element, moving the element out if the head node is uniquely owned
+    // and cloning it otherwise.
+    fn pop_front(&mut self) -> Option<T> where T: Clone {
+        self.head.take().map(|node_rc| {
+            match Rc::try_unwrap(node_rc) {
+                Ok(node) => {
+                    self.head = node.next;
+                    node.elem
+                }
+                Err(node_rc) => {
+                    self.head = node_rc.next.clone();
+                    node_rc.elem.clone()
+                }
+            }
+        })
+    }
+
+    // Copies this list's elements in front of `other`, whose nodes are shared rather than copied.
+    pub fn append(&self, other: &List<T>) -> List<T> where T: Clone {
+        let elems: Vec<&T> = self.iter().collect();
+        elems.into_iter().rev().fold(other.clone(), |list, elem| list.prepend(elem.clone()))
+    }
 }
 
-fn cons<T>(elem: T, list: List<T>) -> List<T> {
+pub fn cons<T>(elem: T, list: List<T>) -> List<T> {
     list.prepend(elem)
 }
 
+// Folds append() from the right, so every list but the last is copied exactly once and the last
+// list's structure is shared.
+pub fn concat<T: Clone>(lists: &[List<T>]) -> List<T> {
+    match lists.split_last() {
+        None => List::new(),
+        Some((last, init)) => init.iter().rev().fold(last.clone(), |acc, list| list.append(&acc)),
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+// Popping requires T: Clone since the head node may be shared with other lists.
+impl<T: Clone> Stack<T> for List<T> {
+    fn push(&mut self, elem: T) {
+        *self = self.prepend(elem);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.head()
+    }
+
+    fn is_empty(&self) -> bool {
+        List::is_empty(self)
+    }
+}
+
+// Only bumps the head's reference count; no T: Clone needed.
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List { head: self.head.clone() }
+    }
+}
+
+// The default recursive drop overflows the stack on long lists. Instead, peel off uniquely-owned
+// nodes in a loop and stop at the first one still shared by another list.
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(node_rc) = link {
+            match Rc::try_unwrap(node_rc) {
+                Ok(mut node) => link = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
 
 ////////////////////////////////////////////////////////////////////////////////
 // By-reference Iter
@@ -71,11 +311,172 @@ impl<'a, T> IntoIterator for &'a List<T> {
     }
 }
 
+// Like Iter, but yields an O(1) handle to the sub-list starting at each node.
+pub struct IterRc<'a, T: 'a> {
+    link: &'a Link<T>,
+}
+
+impl<'a, T> Iterator for IterRc<'a, T> {
+    type Item = List<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.link.as_ref().map(|node| {
+            self.link = &node.next;
+            List { head: Some(node.clone()) }
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// IntoIterator
+
+// Since nodes may be shared with other lists, T: Clone is required. Elements are moved out of
+// uniquely-owned nodes and cloned out of shared ones (and every node after a shared one is itself
+// shared).
+pub struct ListIntoIterator<T> {
+    list: List<T>,
+}
+
+impl<T: Clone> Iterator for ListIntoIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+}
+
+impl<T: Clone> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = ListIntoIterator<T>;
+
+    fn into_iter(self) -> ListIntoIterator<T> {
+        ListIntoIterator { list: self }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversions
+
+// Iterates in the same order as the vector.
+impl<T> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> Self {
+        List::from_double_ended(vec)
+    }
+}
+
+impl<T> From<mutable_doubly_linked_list::List<T>> for List<T> {
+    fn from(doubly: mutable_doubly_linked_list::List<T>) -> Self {
+        List::from_double_ended(doubly)
+    }
+}
+
+// The singly-linked list only drains from the front, so buffer the elements to preserve order.
+impl<T> From<mutable_linked_list::List<T>> for List<T> {
+    fn from(singly: mutable_linked_list::List<T>) -> Self {
+        List::from(singly.into_iter().collect::<Vec<T>>())
+    }
+}
+
+// Moves out the elements of uniquely-owned nodes and clones the rest, like into_iter(). See
+// to_doubly() for the always-cloning, non-consuming version.
+impl<T: Clone> IntoDoubly<T> for List<T> {
+    fn into_doubly(self) -> mutable_doubly_linked_list::List<T> {
+        let mut doubly = mutable_doubly_linked_list::List::new();
+        for elem in self {
+            doubly.push_back(elem);
+        }
+        doubly
+    }
+}
+
+// Since the list can only be built from the back, buffer the elements first to preserve order.
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        List::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Comparison and hashing
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &List<T>) -> bool {
+        // Shared structure means identical content.
+        if self.shares_with(other) {
+            return true;
+        }
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+// Lexicographic, like Vec: a strict prefix orders before the longer list.
+impl<T: PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &List<T>) -> Option<Ordering> {
+        // Must agree with the shared-structure fast path in eq().
+        if self.shares_with(other) {
+            return Some(Ordering::Equal);
+        }
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for List<T> {
+    fn cmp(&self, other: &List<T>) -> Ordering {
+        if self.shares_with(other) {
+            return Ordering::Equal;
+        }
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Formatting
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Serde (as a head-to-tail sequence)
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for List<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for List<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(List::from)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod test {
-    use super::{List, cons};
+    use super::{List, concat, cons};
+    use std::prelude::v1::*;
+    use mutable_doubly_linked_list;
+    use mutable_linked_list;
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+    use std::rc::Rc;
 
     #[test]
     fn test_construction() {
@@ -111,4 +512,487 @@ mod test {
             i -= 1;
         }
     }
+
+    #[test]
+    fn test_len() {
+        let list = List::new();
+        assert_eq!(0, list.len());
+        assert!(list.is_empty());
+        let list = cons(3, cons(2, cons(1, list)));
+        assert_eq!(3, list.len());
+        assert!(!list.is_empty());
+        assert_eq!(2, list.tail().len());
+        assert_eq!(0, list.tail().tail().tail().len());
+        assert_eq!(4, list.prepend(4).len());
+        assert_eq!(3, list.len());
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        list = cons(2, cons(1, list));
+        assert!(!list.is_empty());
+        list = list.tail();
+        assert!(!list.is_empty());
+        list = list.tail();
+        assert!(list.is_empty());
+        assert!(list.tail().is_empty());
+    }
+
+    #[test]
+    fn test_drop_long() {
+        let mut list = List::new();
+        for i in 0..1000000 {
+            list = list.prepend(i);
+        }
+        drop(list);
+    }
+
+    #[test]
+    fn test_drop_shared_tail() {
+        let tail = cons(2, cons(1, List::new()));
+        let list1 = cons(4, cons(3, tail.tail().prepend(2)));
+        let list2 = tail.prepend(3);
+        drop(list1);
+        drop(tail);
+        assert_eq!(vec![3, 2, 1], list2.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_debug() {
+        let list = cons(3, cons(2, cons(1, List::new())));
+        assert_eq!("[3, 2, 1]", format!("{:?}", list));
+        assert_eq!("[]", format!("{:?}", List::<i32>::new()));
+    }
+
+    #[test]
+    fn test_eq() {
+        let list1 = cons(3, cons(2, cons(1, List::new())));
+        let list2 = List::new().prepend(1).prepend(2).prepend(3);
+        assert_eq!(list1, list2);
+        assert_eq!(List::<i32>::new(), List::new());
+
+        // Same head node.
+        assert_eq!(list1.tail(), list1.tail());
+
+        // Same length, differing elements.
+        assert!(list1 != cons(4, list1.tail()));
+        assert!(list1 != cons(3, cons(2, cons(0, List::new()))));
+
+        // Different lengths.
+        assert!(list1 != list1.tail());
+        assert!(list1 != list1.prepend(4));
+        assert!(list1 != List::new());
+    }
+
+    #[test]
+    fn test_ord() {
+        fn list(elems: &[i32]) -> List<i32> {
+            elems.iter().rev().fold(List::new(), |list, elem| list.prepend(*elem))
+        }
+        let cases: &[(&[i32], &[i32])] = &[
+            (&[], &[]), (&[], &[1]), (&[1], &[]), (&[1, 2], &[1, 2]), (&[1, 2], &[1, 3]),
+            (&[1, 3], &[1, 2]), (&[1, 2], &[1, 2, 0]), (&[2], &[1, 2, 3]),
+        ];
+        for &(a, b) in cases {
+            assert_eq!(a.cmp(b), list(a).cmp(&list(b)));
+            assert_eq!(a.partial_cmp(b), list(a).partial_cmp(&list(b)));
+        }
+        assert!(list(&[]) < list(&[0]));
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut set = HashSet::new();
+        set.insert(List::new().prepend(1).prepend(2));
+        set.insert(cons(2, cons(1, List::new())));
+        assert_eq!(1, set.len());
+        set.insert(cons(1, cons(2, List::new())));
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let list: List<_> = (1..=3).collect();
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(3, list.len());
+        let list: List<i32> = None.into_iter().collect();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_reverse() {
+        let list: List<_> = (1..=3).collect();
+        let reversed = list.reverse();
+        assert_eq!(vec![3, 2, 1], reversed.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+        assert!(List::<i32>::new().reverse().is_empty());
+    }
+
+    #[test]
+    fn test_append() {
+        let list: List<_> = (1..=2).collect();
+        let other: List<_> = (3..=5).collect();
+        let appended = list.append(&other);
+        assert_eq!(vec![1, 2, 3, 4, 5], appended.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(5, appended.len());
+        assert!(Rc::ptr_eq(appended.tail().tail().head.as_ref().unwrap(),
+                           other.head.as_ref().unwrap()));
+
+        let appended = List::new().append(&other);
+        assert!(Rc::ptr_eq(appended.head.as_ref().unwrap(), other.head.as_ref().unwrap()));
+        assert_eq!(list, list.append(&List::new()));
+    }
+
+    #[test]
+    fn test_clone() {
+        struct NotClone(i32);
+        let list = cons(NotClone(2), cons(NotClone(1), List::new()));
+        let cloned = list.clone();
+        assert!(list.iter().map(|e| e.0).eq(cloned.iter().map(|e| e.0)));
+        assert!(Rc::ptr_eq(list.head.as_ref().unwrap(), cloned.head.as_ref().unwrap()));
+        drop(list);
+        assert_eq!(vec![2, 1], cloned.iter().map(|e| e.0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map() {
+        let list: List<_> = (1..=3).collect();
+        let mapped = list.map(|val| val.to_string());
+        assert_eq!(vec!["1", "2", "3"], mapped.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+        assert_eq!(3, mapped.len());
+    }
+
+    #[test]
+    fn test_fold() {
+        let list: List<_> = (1..=4).collect();
+        assert_eq!(10, list.fold(0, |sum, val| sum + val));
+        assert_eq!("1234", list.fold(String::new(), |acc, val| acc + &val.to_string()));
+        assert_eq!(0, List::new().fold(0, |sum, val: &i32| sum + val));
+    }
+
+    #[test]
+    fn test_foldr() {
+        let list: List<_> = (1..=4).collect();
+        assert_eq!("4321", list.foldr(String::new(), |val, acc| acc + &val.to_string()));
+        assert_eq!("(1 (2 (3 (4 ()))))",
+                   list.foldr("()".to_string(), |val, acc| format!("({} {})", val, acc)));
+
+        let list: List<_> = (0..1000000).collect();
+        assert_eq!(1000000, list.foldr(0, |_, count| count + 1));
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let list = cons(3, cons(2, cons(1, List::new())));
+        assert_eq!(vec![3, 2, 1], list.to_vec());
+        assert_eq!(Vec::<i32>::new(), List::<i32>::new().to_vec());
+    }
+
+    #[test]
+    fn test_from_slice_vec() {
+        assert_eq!(vec![1, 2, 3], List::from_slice(&[1, 2, 3]).to_vec());
+        assert_eq!(vec![1, 2, 3], List::from(vec![1, 2, 3]).to_vec());
+        assert!(List::<i32>::from_slice(&[]).is_empty());
+        assert!(List::<i32>::from(vec![]).is_empty());
+        assert_eq!(3, List::from(vec![1, 2, 3]).len());
+    }
+
+    #[test]
+    fn test_get() {
+        let list: List<_> = (1..=3).collect();
+        assert_eq!(Some(&1), list.get(0));
+        assert_eq!(Some(&3), list.get(2));
+        assert_eq!(None, list.get(3));
+        assert_eq!(None, List::<i32>::new().get(0));
+    }
+
+    #[test]
+    fn test_nth_tail() {
+        let list: List<_> = (1..=3).collect();
+        assert_eq!(list, list.nth_tail(0));
+        let sub = list.nth_tail(1);
+        assert_eq!(vec![2, 3], sub.to_vec());
+        assert!(Rc::ptr_eq(sub.head.as_ref().unwrap(), list.tail().head.as_ref().unwrap()));
+        assert!(list.nth_tail(3).is_empty());
+        assert!(list.nth_tail(10).is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let list: List<_> = (1..=3).collect();
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+        assert!(!List::new().contains(&1));
+    }
+
+    #[test]
+    fn test_take() {
+        let list: List<_> = (1..=3).collect();
+        assert_eq!(vec![1, 2], list.take(2).to_vec());
+        assert_eq!(vec![1, 2, 3], list.take(3).to_vec());
+        assert_eq!(vec![1, 2, 3], list.take(4).to_vec());
+        assert!(list.take(0).is_empty());
+        assert!(List::<i32>::new().take(1).is_empty());
+    }
+
+    #[test]
+    fn test_drop_n() {
+        let list: List<_> = (1..=4).collect();
+        let dropped = list.drop_n(2);
+        assert_eq!(vec![3, 4], dropped.to_vec());
+        assert!(Rc::ptr_eq(dropped.head.as_ref().unwrap(),
+                           list.tail().tail().head.as_ref().unwrap()));
+        assert_eq!(list, list.drop_n(0));
+        assert!(list.drop_n(4).is_empty());
+        assert!(list.drop_n(5).is_empty());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let list: List<_> = (1..=4).collect();
+
+        let (front, back) = list.split_at(0);
+        assert!(front.is_empty());
+        assert!(Rc::ptr_eq(back.head.as_ref().unwrap(), list.head.as_ref().unwrap()));
+
+        let (front, back) = list.split_at(2);
+        assert_eq!(vec![1, 2], front.to_vec());
+        assert_eq!(vec![3, 4], back.to_vec());
+        assert!(Rc::ptr_eq(back.head.as_ref().unwrap(), list.nth_tail(2).head.as_ref().unwrap()));
+
+        let (front, back) = list.split_at(5);
+        assert_eq!(list, front);
+        assert!(back.is_empty());
+    }
+
+    #[test]
+    fn test_concat() {
+        let lists = [List::from(vec![1, 2]), List::new(), List::from(vec![3]), List::from(vec![4, 5])];
+        let list = concat(&lists);
+        assert_eq!(vec![1, 2, 3, 4, 5], list.to_vec());
+        assert!(Rc::ptr_eq(list.nth_tail(3).head.as_ref().unwrap(),
+                           lists[3].head.as_ref().unwrap()));
+        assert!(concat::<i32>(&[]).is_empty());
+        assert!(concat(&[List::<i32>::new(), List::new()]).is_empty());
+    }
+
+    #[test]
+    fn test_shares_with() {
+        let list: List<_> = (1..=3).collect();
+        let other = list.clone();
+        assert!(list.shares_with(&other));
+        assert_eq!(list, other);
+
+        let distinct: List<_> = (1..=3).collect();
+        assert!(!list.shares_with(&distinct));
+        assert_eq!(list, distinct);
+
+        assert!(!list.shares_with(&list.tail()));
+        assert!(List::<i32>::new().shares_with(&List::new()));
+        assert!(!list.shares_with(&List::new()));
+    }
+
+    #[test]
+    fn test_eq_fast_path() {
+        // NaN != NaN, so equality of a NaN-containing list with itself can only come from sharing.
+        let list = List::from(vec![1.0, f64::NAN]);
+        assert_eq!(list, list.clone());
+        assert_eq!(Some(Ordering::Equal), list.partial_cmp(&list.clone()));
+        assert!(list != List::from(vec![1.0, f64::NAN]));
+    }
+
+    #[test]
+    fn test_partial_cmp_fast_path() {
+        let list = List::from(vec![f64::NAN, 1.0]);
+        assert_eq!(Some(Ordering::Equal), list.tail().partial_cmp(&list.nth_tail(1)));
+        assert_eq!(None, list.partial_cmp(&List::from(vec![f64::NAN, 1.0])));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let list = List::from(vec![1, 2, 3]);
+        let json = ::serde_json::to_string(&list).unwrap();
+        assert_eq!("[1,2,3]", json);
+        let list: List<i32> = ::serde_json::from_str(&json).unwrap();
+        assert_eq!(vec![1, 2, 3], list.to_vec());
+        assert_eq!(3, list.len());
+    }
+
+    #[test]
+    fn test_head_tail() {
+        let mut list: List<_> = (1..=3).collect();
+        let mut seen = vec![];
+        while let Some((head, tail)) = list.head_tail() {
+            seen.push(*head);
+            assert!(tail.shares_with(&list.tail()));
+            list = tail;
+        }
+        assert_eq!(vec![1, 2, 3], seen);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_filter() {
+        let list: List<_> = (1..=4).collect();
+        assert_eq!(vec![2, 4], list.filter(|val| val % 2 == 0).to_vec());
+        assert!(list.filter(|_| false).is_empty());
+        assert_eq!(list, list.filter(|_| true));
+    }
+
+    #[test]
+    fn test_zip() {
+        let list1: List<_> = (1..=3).collect();
+        let list2 = List::from(vec!["a", "b"]);
+        assert_eq!(vec![(&1, &"a"), (&2, &"b")], list1.zip(&list2).collect::<Vec<_>>());
+        assert_eq!(vec![(&"a", &1), (&"b", &2)], list2.zip(&list1).collect::<Vec<_>>());
+        assert_eq!(0, list1.zip(&List::<i32>::new()).count());
+    }
+
+    #[test]
+    fn test_try_pop_unique() {
+        struct NotClone(i32);
+        let list = cons(NotClone(2), cons(NotClone(1), List::new()));
+        let (elem, list) = list.try_pop().ok().unwrap();
+        assert_eq!(2, elem.0);
+        let (elem, list) = list.try_pop().ok().unwrap();
+        assert_eq!(1, elem.0);
+        assert!(list.try_pop().err().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_try_pop_shared() {
+        let list: List<_> = (1..=3).collect();
+        let other = list.clone();
+        let list = list.try_pop().err().unwrap();
+        assert!(list.shares_with(&other));
+        assert_eq!(vec![1, 2, 3], list.to_vec());
+
+        // Only the head node needs to be uniquely owned.
+        let list = other.tail().prepend(0);
+        let (elem, tail) = list.try_pop().ok().unwrap();
+        assert_eq!(0, elem);
+        assert!(tail.shares_with(&other.tail()));
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Wrapper {
+            list: List<i32>,
+        }
+
+        let wrapper = Wrapper::default();
+        assert!(wrapper.list.is_empty());
+        assert_eq!(Some(&1), wrapper.list.prepend(1).head());
+    }
+
+    #[test]
+    fn test_into_iter_unique() {
+        let list: List<_> = (1..=3).map(Rc::new).collect();
+        let elems: Vec<Rc<i32>> = list.into_iter().collect();
+        assert_eq!(vec![1, 2, 3], elems.iter().map(|elem| **elem).collect::<Vec<_>>());
+        // Moved rather than cloned.
+        assert!(elems.iter().all(|elem| Rc::strong_count(elem) == 1));
+    }
+
+    #[test]
+    fn test_into_iter_shared() {
+        let shared: List<_> = (2..=3).map(Rc::new).collect();
+        let list = shared.prepend(Rc::new(1));
+        let elems: Vec<Rc<i32>> = list.into_iter().collect();
+        assert_eq!(vec![1, 2, 3], elems.iter().map(|elem| **elem).collect::<Vec<_>>());
+        assert_eq!(vec![1, 2, 2], elems.iter().map(Rc::strong_count).collect::<Vec<_>>());
+        assert_eq!(2, shared.len());
+    }
+
+    #[test]
+    fn test_sharing_introspection() {
+        let common: List<_> = (1..=3).collect();
+        assert_eq!(1, common.head_strong_count());
+        assert_eq!(0, List::<i32>::new().head_strong_count());
+
+        let list1 = common.prepend(0);
+        let list2 = common.prepend(10).prepend(20);
+        assert_eq!(3, common.head_strong_count());
+        assert_eq!(3, list1.shared_suffix_len(&list2));
+        assert_eq!(3, list2.shared_suffix_len(&list1));
+        assert_eq!(3, list1.shared_suffix_len(&common));
+        assert_eq!(4, list1.shared_suffix_len(&list1.clone()));
+
+        let distinct: List<_> = (1..=3).collect();
+        assert_eq!(0, list1.shared_suffix_len(&distinct));
+        assert_eq!(0, list1.shared_suffix_len(&List::new()));
+    }
+
+    #[test]
+    fn test_to_doubly() {
+        let list = List::from(vec![1, 2, 3]);
+        let doubly = list.to_doubly();
+        assert_eq!(3, doubly.len());
+        assert_eq!(vec![1, 2, 3], doubly.iter().cloned().collect::<Vec<_>>());
+        let round_trip: List<_> = doubly.into_iter().collect();
+        assert_eq!(list, round_trip);
+        assert!(List::<i32>::new().to_doubly().is_empty());
+    }
+
+    #[test]
+    fn test_from_doubly() {
+        let mut doubly = mutable_doubly_linked_list::List::new();
+        doubly.push_back(2);
+        doubly.push_back(3);
+        doubly.push_front(1);
+        let list = List::from(doubly);
+        assert_eq!(vec![1, 2, 3], list.to_vec());
+        assert_eq!(3, list.len());
+        let snapshot = list.clone();
+        assert!(snapshot.shares_with(&list));
+        assert_eq!(2, list.head_strong_count());
+    }
+
+    #[test]
+    fn test_from_singly() {
+        let singly = mutable_linked_list::List::from_slice(&[1, 2, 3]);
+        let list = List::from(singly);
+        assert_eq!(vec![1, 2, 3], list.to_vec());
+        assert_eq!(3, list.len());
+
+        // And back again.
+        let singly = mutable_linked_list::List::from(&list);
+        assert_eq!(vec![1, 2, 3], singly.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(list, List::from(singly));
+        assert_eq!(List::<i32>::new(), List::from(mutable_linked_list::List::new()));
+    }
+
+    #[test]
+    fn test_iter_rc() {
+        let list = List::from_slice(&[1, 2, 3]);
+        let mut link = &list.head;
+        let mut count = 0;
+        for (n, sublist) in list.iter_rc().enumerate() {
+            let node = link.as_ref().unwrap();
+            assert!(Rc::ptr_eq(node, sublist.head.as_ref().unwrap()));
+            assert_eq!(list.nth_tail(n).to_vec(), sublist.to_vec());
+            link = &node.next;
+            count += 1;
+        }
+        assert_eq!(3, count);
+
+        // Handles outlive the iteration and keep their nodes shared.
+        let tails: Vec<List<i32>> = list.iter_rc().collect();
+        assert_eq!(vec![3], tails[2].to_vec());
+        assert_eq!(2, tails[1].head_strong_count());
+        assert_eq!(0, List::<i32>::new().iter_rc().count());
+    }
+
+    #[test]
+    fn test_from_double_ended() {
+        let list = List::from_double_ended(1..4);
+        assert_eq!(vec![1, 2, 3], list.to_vec());
+        assert_eq!(3, list.len());
+        assert!(List::<i32>::from_double_ended(vec![]).is_empty());
+    }
 }
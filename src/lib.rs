@@ -1,3 +1,202 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Everything is written against core and alloc so that the crate also builds without std.
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate core;
+// Tests always have std available, even when the crate itself is built without it.
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
 pub mod mutable_linked_list;
 pub mod immutable_linked_list;
 pub mod mutable_doubly_linked_list;
+pub mod arena_doubly_linked_list;
+
+pub use immutable_linked_list::{cons, List as PersistentList};
+
+// A LIFO interface shared by the singly-linked and persistent lists. The persistent list
+// implements it by replacing itself with the functionally prepended or tail list.
+pub trait Stack<T> {
+    fn push(&mut self, elem: T);
+    fn pop(&mut self) -> Option<T>;
+    fn peek(&self) -> Option<&T>;
+    fn is_empty(&self) -> bool;
+}
+
+// A double-ended queue interface, implemented by the doubly-linked and arena-backed lists.
+pub trait Deque<T> {
+    fn push_front(&mut self, elem: T);
+    fn push_back(&mut self, elem: T);
+    fn pop_front(&mut self) -> Option<T>;
+    fn pop_back(&mut self) -> Option<T>;
+    fn front(&self) -> Option<&T>;
+    fn back(&self) -> Option<&T>;
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Uniform consuming conversions into the doubly-linked and persistent lists, implemented by each
+// of the other lists.
+pub trait IntoDoubly<T> {
+    fn into_doubly(self) -> mutable_doubly_linked_list::List<T>;
+}
+
+pub trait IntoPersistent<T> {
+    fn into_persistent(self) -> immutable_linked_list::List<T>;
+}
+
+/// Element-wise equality of two sequences, including their lengths. This is the canonical way to
+/// compare lists of different backing types, e.g. `iter_eq(&singly, &doubly)`.
+pub fn iter_eq<'a, T: PartialEq + 'a, I, J>(a: I, b: J) -> bool
+    where I: IntoIterator<Item = &'a T>, J: IntoIterator<Item = &'a T>
+{
+    a.into_iter().eq(b)
+}
+
+// Distinct names for the lists so they can be imported together.
+pub mod prelude {
+    pub use arena_doubly_linked_list::ArenaList;
+    pub use immutable_linked_list::List as PersistentList;
+    pub use mutable_doubly_linked_list::List as DoublyLinkedList;
+    pub use mutable_linked_list::List as SinglyLinkedList;
+    pub use {Deque, IntoDoubly, IntoPersistent, Stack};
+}
+
+#[cfg(test)]
+mod test {
+    use iter_eq;
+    use prelude::*;
+    use std::prelude::v1::*;
+
+    fn check_stack<S: Stack<i32>>(mut stack: S) {
+        assert!(stack.is_empty());
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert!(!stack.is_empty());
+        assert_eq!(Some(&3), stack.peek());
+        assert_eq!(Some(3), stack.pop());
+        assert_eq!(Some(&2), stack.peek());
+        assert_eq!(Some(2), stack.pop());
+        assert_eq!(Some(1), stack.pop());
+        assert_eq!(None, stack.pop());
+        assert_eq!(None, stack.peek());
+        assert!(stack.is_empty());
+    }
+
+    fn check_deque<D: Deque<i32>>(mut deque: D) {
+        assert!(deque.is_empty());
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(3, deque.len());
+        assert_eq!(Some(&1), deque.front());
+        assert_eq!(Some(&3), deque.back());
+        assert_eq!(Some(3), deque.pop_back());
+        assert_eq!(Some(1), deque.pop_front());
+        assert_eq!(Some(&2), deque.front());
+        assert_eq!(Some(&2), deque.back());
+        assert_eq!(Some(2), deque.pop_back());
+        assert_eq!(None, deque.pop_front());
+        assert_eq!(None, deque.pop_back());
+        assert_eq!(None, deque.front());
+        assert_eq!(0, deque.len());
+    }
+
+    #[test]
+    fn test_singly_linked_stack() {
+        check_stack(SinglyLinkedList::new());
+    }
+
+    #[test]
+    fn test_persistent_stack() {
+        check_stack(PersistentList::new());
+
+        // Popping a shared list clones instead of disturbing the other handle.
+        let other = PersistentList::new().prepend(1).prepend(2);
+        let mut stack = other.clone();
+        assert_eq!(Some(2), Stack::pop(&mut stack));
+        assert_eq!(vec![1], stack.to_vec());
+        assert_eq!(vec![2, 1], other.to_vec());
+    }
+
+    #[test]
+    fn test_doubly_linked_deque() {
+        check_deque(DoublyLinkedList::new());
+    }
+
+    #[test]
+    fn test_arena_deque() {
+        check_deque(ArenaList::new());
+    }
+
+    #[test]
+    fn test_iter_eq() {
+        let singly = SinglyLinkedList::from_slice(&[1, 2, 3]);
+        let persistent = PersistentList::from_slice(&[1, 2, 3]);
+        let mut doubly = DoublyLinkedList::new();
+        let mut arena = ArenaList::new();
+        for i in 1..4 {
+            doubly.push_back(i);
+            arena.push_back(i);
+        }
+
+        assert!(iter_eq(&singly, &persistent));
+        assert!(iter_eq(&singly, &doubly));
+        assert!(iter_eq(&singly, &arena));
+        assert!(iter_eq(&persistent, &doubly));
+        assert!(iter_eq(&persistent, &arena));
+        assert!(iter_eq(&doubly, &arena));
+
+        // Differing elements or lengths.
+        let shorter = persistent.tail();
+        assert!(!iter_eq(&shorter, &doubly));
+        assert!(!iter_eq(&doubly, &shorter));
+        *doubly.peek_back_mut().unwrap() = 4;
+        assert!(!iter_eq(&singly, &doubly));
+        assert!(iter_eq(&SinglyLinkedList::<i32>::new(), &ArenaList::new()));
+    }
+
+    #[test]
+    fn test_into_doubly() {
+        let expected = [1, 2, 3];
+        let doubly = SinglyLinkedList::from_slice(&expected).into_doubly();
+        assert!(iter_eq(&expected, &doubly));
+
+        let persistent = PersistentList::from_slice(&expected);
+        let shared = persistent.tail();
+        let doubly = persistent.into_doubly();
+        assert!(iter_eq(&expected, &doubly));
+        assert_eq!(vec![2, 3], shared.to_vec());
+
+        let mut arena = ArenaList::new();
+        arena.push_back(2);
+        arena.push_back(3);
+        arena.push_front(1);
+        assert!(iter_eq(&expected, &arena.into_doubly()));
+    }
+
+    #[test]
+    fn test_into_persistent() {
+        let expected = [1, 2, 3];
+        let persistent = SinglyLinkedList::from_slice(&expected).into_persistent();
+        assert!(iter_eq(&expected, &persistent));
+
+        let mut doubly = DoublyLinkedList::new();
+        let mut arena = ArenaList::new();
+        for &i in &expected {
+            doubly.push_back(i);
+            arena.push_back(i);
+        }
+        assert!(iter_eq(&expected, &doubly.into_persistent()));
+        assert!(iter_eq(&expected, &arena.into_persistent()));
+    }
+}
@@ -1,11 +1,16 @@
-/// This is a doubly-linked list implementation with forward pointers being owners and backward
-/// pointers being unsafe pointers. The goal is to write the unsafe code involving the backward
-/// links as similarly as the safe code for the forward links to avoid rampant stupidity.
-
-use std::borrow::Borrow;
-use std::borrow::BorrowMut;
-use std::iter::IntoIterator;
-use std::ptr;
+//! This is a doubly-linked list implementation with forward pointers being owners and backward
+//! pointers being unsafe pointers. The goal is to write the unsafe code involving the backward
+//! links as similarly as the safe code for the forward links to avoid rampant stupidity.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::borrow::BorrowMut;
+use core::iter::IntoIterator;
+use core::ptr;
+use immutable_linked_list;
+use mutable_linked_list;
+use {Deque, IntoPersistent};
 
 ////////////////////////////////////////////////////////////////////////////////
 // List implementation
@@ -13,6 +18,7 @@ use std::ptr;
 pub struct List<T> {
     head: Link<T>,
     tail: LinkPtr<T>,
+    len: usize,
 }
 
 type Link<T> = Option<Box<Node<T>>>;
@@ -30,18 +36,18 @@ struct Node<T> {
 // While Option<&T> is Copy, Option<&mut T> is not, so taking Options by reference for consistency.
 
 fn eq_ref_opt<T>(ref1: &T, opt_ref2: &Option<&T>) -> bool {
-    match opt_ref2 {
-        // Need to convert to *const for referential equality.
-        &Some(ref ref2) => ref1 as *const T == *ref2 as *const T,
-        &None => false
+    match *opt_ref2 {
+        // Referential (not value) equality.
+        Some(ref2) => ptr::eq(ref1, ref2),
+        None => false
     }
 }
 
 fn eq_mut_ref_opt<T>(ref1: &mut T, opt_ref2: &Option<&mut T>) -> bool {
     match opt_ref2 {
-        // Need to convert to *const for referential equality.
-        &Some(ref ref2) => ref1 as *const T == *ref2 as *const T,
-        &None => false
+        // Referential (not value) equality.
+        Some(ref2) => ptr::eq(ref1, &**ref2),
+        None => false
     }
 }
 
@@ -69,15 +75,16 @@ trait OptionRef<T> {
 // Option<Box> -> Option<&>. In fact, can be used instead of as_ref() and as_mut() in most places.
 impl<T, B: Borrow<T> + BorrowMut<T>> OptionRef<T> for Option<B> {
     fn to_ref(&self) -> Option<&T> {
-        self.as_ref().take().map(|borrowable| {borrowable.borrow()})
+        self.as_ref().map(|borrowable| {borrowable.borrow()})
     }
     fn to_mut(&mut self) -> Option<&mut T> {
-        self.as_mut().take().map(|borrowable| {borrowable.borrow_mut()})
+        self.as_mut().map(|borrowable| {borrowable.borrow_mut()})
     }
 }
 
 // Make unsafe pointers behave similar to Option<Box> above. This is unsafe because the output
 // reference isn't unbounded and the caller is responsible for bounding it appropriately.
+#[allow(clippy::wrong_self_convention)]
 trait UnsafeOptionRef<T> {
     unsafe fn to_ref<'b>(self) -> Option<&'b T>;
     unsafe fn to_mut<'b>(self) -> Option<&'b mut T>;
@@ -99,17 +106,17 @@ impl<T> UnsafeOptionRef<T> for *const T {
 }
 
 ////////////////////////////////////////////////////////////
-/// Doubly-linked list
+// Doubly-linked list
 
 impl<T> List<T> {
     pub fn new() -> Self {
-        List { head: None, tail: ptr::null() }
+        List { head: None, tail: ptr::null(), len: 0 }
     }
 
     // PUSH
     pub fn push_front(&mut self, elem: T) {
         let mut node_box = Box::new(Node {
-            elem: elem,
+            elem,
             next: self.head.take(),
             prev: ptr::null()
         });
@@ -119,11 +126,12 @@ impl<T> List<T> {
             Some(old_head_ref) => old_head_ref.prev = node_ptr
         }
         self.head = Some(node_box);
+        self.len += 1;
     }
 
     pub fn push_back(&mut self, elem: T) {
         let node_box = Box::new(Node {
-            elem: elem,
+            elem,
             next: None,
             prev: self.tail
         });
@@ -133,6 +141,7 @@ impl<T> List<T> {
             Some(old_tail_ref) => old_tail_ref.next = Some(node_box)
         }
         self.tail = node_ptr;
+        self.len += 1;
     }
 
     // POP
@@ -144,6 +153,7 @@ impl<T> List<T> {
                 None => self.tail = ptr::null(),
                 Some(node_box) => node_box.prev = ptr::null()
             }
+            self.len -= 1;
             node.elem
         })
     }
@@ -157,6 +167,7 @@ impl<T> List<T> {
                 None => self.head.take(),
                 Some(node_ref) => node_ref.next.take()
             };
+            self.len -= 1;
             node_opt.unwrap().elem // Ideally, should use unwrap_unchecked().
         })
     }
@@ -179,15 +190,32 @@ impl<T> List<T> {
         unsafe { self.tail.to_mut() }.map(|node| { &mut node.elem })
     }
 
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Moves the elements out front to back, so no Clone is needed.
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
+        while let Some(elem) = self.pop_front() {
+            vec.push(elem);
+        }
+        vec
+    }
+
     // ITER
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             front_link: self.head.to_ref(),
             back_link: unsafe { self.tail.to_ref() }
         }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
             front_link: self.head.to_mut(),
             back_link: unsafe { self.tail.to_mut() }
@@ -195,6 +223,42 @@ impl<T> List<T> {
     }
 }
 
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+impl<T> Deque<T> for List<T> {
+    fn push_front(&mut self, elem: T) {
+        List::push_front(self, elem);
+    }
+
+    fn push_back(&mut self, elem: T) {
+        List::push_back(self, elem);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        List::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        List::pop_back(self)
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.peek_front()
+    }
+
+    fn back(&self) -> Option<&T> {
+        self.peek_back()
+    }
+
+    fn len(&self) -> usize {
+        List::len(self)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // By-reference Iter
 
@@ -292,6 +356,14 @@ impl<T> DoubleEndedIterator for ListIntoIterator<T> {
     }
 }
 
+// Dropping the remaining chain of boxed nodes as-is would recurse once per node, so drain them one
+// at a time. The wrapped list is then empty by the time it is dropped itself.
+impl<T> Drop for ListIntoIterator<T> {
+    fn drop(&mut self) {
+        while self.list.pop_front().is_some() {}
+    }
+}
+
 impl<T> IntoIterator for List<T> {
     type Item = T;
     type IntoIter = ListIntoIterator<T>;
@@ -319,11 +391,32 @@ impl<'a, T> IntoIterator for &'a mut List<T> {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Conversions from the other lists
+
+impl<T> From<mutable_linked_list::List<T>> for List<T> {
+    fn from(singly: mutable_linked_list::List<T>) -> Self {
+        let mut list = List::new();
+        for elem in singly {
+            list.push_back(elem);
+        }
+        list
+    }
+}
+
+impl<T> IntoPersistent<T> for List<T> {
+    fn into_persistent(self) -> immutable_linked_list::List<T> {
+        immutable_linked_list::List::from(self)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod test {
     use super::List;
+    use std::prelude::v1::*;
+    use mutable_linked_list;
 
     #[test]
     fn test_push_pop_front() {
@@ -421,6 +514,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::explicit_counter_loop)]
     fn test_iter() {
         let mut list = List::new();
         list.push_back(1);
@@ -448,6 +542,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::explicit_counter_loop)]
     fn test_iter_mut() {
         let mut list = List::new();
         list.push_front(1);
@@ -471,5 +566,61 @@ mod test {
             i -= 1;
         }
     }
-}
 
+    #[test]
+    fn test_len() {
+        let mut list = List::new();
+        assert_eq!(0, list.len());
+        assert!(list.is_empty());
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(3, list.len());
+        list.pop_back();
+        assert_eq!(2, list.len());
+        list.pop_front();
+        list.pop_front();
+        assert_eq!(0, list.len());
+        list.pop_front();
+        list.pop_back();
+        assert_eq!(0, list.len());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_from_singly() {
+        let singly = mutable_linked_list::List::from_slice(&[1, 2, 3]);
+        let list = List::from(singly);
+        assert_eq!(3, list.len());
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+        assert!(List::from(mutable_linked_list::List::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_into_vec() {
+        #[derive(Debug, PartialEq)]
+        struct NoClone(i32);
+
+        let mut list = List::new();
+        list.push_back(Box::new(NoClone(2)));
+        list.push_back(Box::new(NoClone(3)));
+        list.push_front(Box::new(NoClone(1)));
+        let vec = list.into_vec();
+        assert_eq!(vec![Box::new(NoClone(1)), Box::new(NoClone(2)), Box::new(NoClone(3))], vec);
+        assert!(vec.capacity() >= 3);
+        assert_eq!(Vec::<Box<NoClone>>::new(), List::new().into_vec());
+    }
+
+    #[test]
+    fn test_drop_partial_into_iter() {
+        let mut list = List::new();
+        for i in 0..1_000_000 {
+            list.push_back(i);
+        }
+        let mut iter = list.into_iter();
+        assert_eq!(Some(0), iter.next());
+        assert_eq!(Some(999_999), iter.next_back());
+        assert_eq!(Some(1), iter.next());
+        drop(iter);
+    }
+}
@@ -1,4 +1,15 @@
-use std::iter::IntoIterator;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::iter::IntoIterator;
+use immutable_linked_list;
+use mutable_doubly_linked_list;
+use {IntoDoubly, IntoPersistent, Stack};
+
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, Serializer};
 
 ////////////////////////////////////////////////////////////////////////////////
 // List implementation
@@ -19,9 +30,22 @@ impl<T> List<T> {
         List { head: None }
     }
 
-    // TODO: Figure out how to return self.
-    pub fn push(&mut self, elem: T) {
-        self.head = Some(Box::new(Node { elem: elem, next: self.head.take() }));
+    pub fn push(&mut self, elem: T) -> &mut Self {
+        self.head = Some(Box::new(Node { elem, next: self.head.take() }));
+        self
+    }
+
+    // By-value variant of push() for fluent construction of an owned list.
+    pub fn with(mut self, elem: T) -> Self {
+        self.push(elem);
+        self
+    }
+
+    // Iterates in the same order as the slice.
+    pub fn from_slice(slice: &[T]) -> List<T> where T: Clone {
+        let mut list = List::new();
+        list.append_iter(slice.iter().cloned());
+        list
     }
 
     pub fn pop(&mut self) -> Option<T> {
@@ -41,13 +65,176 @@ impl<T> List<T> {
         self.head.as_mut().map(|node| { &mut node.elem })
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter { link: &self.head }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut { next: Some(&mut self.head) }
     }
+
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { link: Some(&mut self.head), started: false }
+    }
+
+    pub fn contains(&self, x: &T) -> bool where T: PartialEq {
+        self.iter().any(|elem| elem == x)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> List<U> {
+        let mut list = List::new();
+        list.append_iter(self.iter().map(f));
+        list
+    }
+
+    pub fn filter_map<U, F: FnMut(&T) -> Option<U>>(&self, f: F) -> List<U> {
+        let mut list = List::new();
+        list.append_iter(self.iter().filter_map(f));
+        list
+    }
+
+    // Stable merge sort that relinks the existing nodes rather than allocating new ones.
+    pub fn sort(&mut self) where T: Ord {
+        let len = self.iter().count();
+        self.head = merge_sort(self.head.take(), len);
+    }
+
+    // Moves the elements out head first, so no Clone is needed.
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+
+    // Consuming counterpart to sort().
+    pub fn into_sorted_vec(self) -> Vec<T> where T: Ord {
+        let mut vec: Vec<T> = self.into_iter().collect();
+        vec.sort();
+        vec
+    }
+
+    // Merges the sorted `other` into this sorted list by relinking nodes, leaving `other` empty.
+    // Stable: on ties, elements of `self` come first.
+    pub fn merge(&mut self, other: &mut List<T>) where T: Ord {
+        self.head = merge_links(self.head.take(), other.head.take());
+    }
+
+    // Removes consecutive repeated elements, keeping the first of each run.
+    pub fn dedup(&mut self) where T: PartialEq {
+        let mut cur = self.head.as_mut();
+        while let Some(node) = cur {
+            while node.next.as_ref().is_some_and(|next| next.elem == node.elem) {
+                let mut dup = node.next.take().unwrap();
+                node.next = dup.next.take();
+            }
+            cur = node.next.as_mut();
+        }
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut link = &mut self.head;
+        while link.is_some() {
+            if f(&link.as_ref().unwrap().elem) {
+                link = &mut link.as_mut().unwrap().next;
+            } else {
+                let mut node = link.take().unwrap();
+                *link = node.next.take();
+            }
+        }
+    }
+
+    // Cuts the list right after the first element matching `pred`, returning the remainder. Returns
+    // None (leaving the list untouched) if nothing matches.
+    pub fn split_after<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<List<T>> {
+        let mut cur = self.head.as_mut();
+        while let Some(node) = cur {
+            if pred(&node.elem) {
+                return Some(List { head: node.next.take() });
+            }
+            cur = node.next.as_mut();
+        }
+        None
+    }
+
+    // Appends the elements to the end of the list in iteration order. Walks the list once to find
+    // the last link, then keeps a handle to it so each append is O(1).
+    fn append_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut link = &mut self.head;
+        while link.is_some() {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        for elem in iter {
+            *link = Some(Box::new(Node { elem, next: None }));
+            link = &mut link.as_mut().unwrap().next;
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+impl<T> Stack<T> for List<T> {
+    fn push(&mut self, elem: T) {
+        List::push(self, elem);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        List::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        List::peek(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Merge sort helpers
+
+// Detaches and returns everything after the first n nodes of the chain.
+fn split_link<T>(link: &mut Link<T>, n: usize) -> Link<T> {
+    let mut link = link;
+    for _ in 0..n {
+        if link.is_none() {
+            break;
+        }
+        link = &mut link.as_mut().unwrap().next;
+    }
+    link.take()
+}
+
+// Merges two sorted chains. Ties are taken from `a` first, which keeps the merge stable.
+fn merge_links<T: Ord>(mut a: Link<T>, mut b: Link<T>) -> Link<T> {
+    let mut head = None;
+    {
+        let mut tail = &mut head;
+        loop {
+            let src = match (a.as_ref(), b.as_ref()) {
+                (Some(a_node), Some(b_node)) =>
+                    if b_node.elem < a_node.elem { &mut b } else { &mut a },
+                (_, None) => { *tail = a; break; }
+                (None, _) => { *tail = b; break; }
+            };
+            let mut node = src.take().unwrap();
+            *src = node.next.take();
+            *tail = Some(node);
+            tail = &mut tail.as_mut().unwrap().next;
+        }
+    }
+    head
+}
+
+fn merge_sort<T: Ord>(mut link: Link<T>, len: usize) -> Link<T> {
+    if len <= 1 {
+        return link;
+    }
+    let mid = len / 2;
+    let back = split_link(&mut link, mid);
+    merge_links(merge_sort(link, mid), merge_sort(back, len - mid))
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -122,6 +309,48 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Mutable cursor
+
+// The cursor starts before the first element, steps onto each element with move_next(), and ends
+// up past the last element. Like IterMut, it stores the link owning the current node (rather than
+// the node itself) so that insertions can be spliced in without a second mutable borrow.
+pub struct CursorMut<'a, T: 'a> {
+    link: Option<&'a mut Link<T>>,  // Always Some(); only None transiently within methods.
+    started: bool,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn move_next(&mut self) {
+        if !self.started {
+            self.started = true;
+            return;
+        }
+        let link = self.link.take().unwrap();
+        self.link = Some(if link.is_some() { &mut link.as_mut().unwrap().next } else { link });
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        if !self.started {
+            return None;
+        }
+        self.link.as_mut().unwrap().as_mut().map(|node| &mut node.elem)
+    }
+
+    // Inserts after the current element, or at the head if the cursor is before the start. Past
+    // the end, the element is appended and the cursor remains past the end.
+    pub fn insert_after(&mut self, elem: T) {
+        let link = self.link.take().unwrap();
+        if self.started && link.is_some() {
+            let next = &mut link.as_mut().unwrap().next;
+            *next = Some(Box::new(Node { elem, next: next.take() }));
+            self.link = Some(link);
+        } else {
+            *link = Some(Box::new(Node { elem, next: link.take() }));
+            self.link = Some(if self.started { &mut link.as_mut().unwrap().next } else { link });
+        }
+    }
+}
 
 ////////////////////////////////////////////////////////////////////////////////
 // IntoIterator
@@ -138,6 +367,13 @@ impl<T> Iterator for ListIntoIterator<T> {
     }
 }
 
+// Drain via pop() so the remaining Box chain isn't dropped recursively.
+impl<T> Drop for ListIntoIterator<T> {
+    fn drop(&mut self) {
+        while self.list.pop().is_some() {}
+    }
+}
+
 impl<T> IntoIterator for List<T> {
     type Item = T;
     type IntoIter = ListIntoIterator<T>;
@@ -165,11 +401,80 @@ impl<'a, T> IntoIterator for &'a mut List<T> {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Conversions
+
+// Clones the persistent list's elements, head first, leaving the persistent list untouched.
+impl<'a, T: Clone> From<&'a immutable_linked_list::List<T>> for List<T> {
+    fn from(persistent: &'a immutable_linked_list::List<T>) -> Self {
+        let mut list = List::new();
+        list.append_iter(persistent.iter().cloned());
+        list
+    }
+}
+
+impl<T> IntoDoubly<T> for List<T> {
+    fn into_doubly(self) -> mutable_doubly_linked_list::List<T> {
+        mutable_doubly_linked_list::List::from(self)
+    }
+}
+
+impl<T> IntoPersistent<T> for List<T> {
+    fn into_persistent(self) -> immutable_linked_list::List<T> {
+        immutable_linked_list::List::from(self)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Equality and hashing
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &List<T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+// Length first, so that hashes of nested lists don't collide by concatenation.
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().count().hash(state);
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Serde (as a head-to-tail sequence)
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for List<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for List<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Append rather than push so that the order round-trips.
+        let mut list = List::new();
+        list.append_iter(Vec::deserialize(deserializer)?);
+        Ok(list)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod test {
     use super::List;
+    use immutable_linked_list;
+    use std::prelude::v1::*;
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
 
     #[test]
     fn test_push_pop() {
@@ -218,6 +523,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::explicit_counter_loop)]
     fn test_iter_mut() {
         let mut list = List::new();
         list.push(1);
@@ -241,4 +547,325 @@ mod test {
             i -= 1;
         }
     }
+
+    #[test]
+    fn test_contains() {
+        let mut list = List::new();
+        assert!(!list.contains(&1));
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert!(list.contains(&1));
+        assert!(list.contains(&2));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+    }
+
+    #[test]
+    fn test_push_chaining() {
+        let mut list = List::new();
+        list.push(1).push(2).push(3);
+        assert_eq!(vec![3, 2, 1], list.iter().cloned().collect::<Vec<_>>());
+
+        let list = List::new().with(1).with(2).with(3);
+        assert_eq!(vec![3, 2, 1], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map() {
+        let list = List::new().with(1).with(2).with(3);
+        let mapped = list.map(|val| val.to_string());
+        assert_eq!(3, mapped.iter().count());
+        assert_eq!(vec!["3", "2", "1"], mapped.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cursor_insert_after_head() {
+        let mut list = List::new().with(3).with(1);
+        {
+            let mut cursor = list.cursor_mut();
+            assert_eq!(None, cursor.current());
+            cursor.move_next();
+            assert_eq!(Some(&mut 1), cursor.current());
+            cursor.insert_after(2);
+            assert_eq!(Some(&mut 1), cursor.current());
+            cursor.move_next();
+            assert_eq!(Some(&mut 2), cursor.current());
+        }
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cursor_insert_after_middle() {
+        let mut list = List::new().with(4).with(3).with(1);
+        {
+            let mut cursor = list.cursor_mut();
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(Some(&mut 3), cursor.current());
+            cursor.insert_after(35);
+            *cursor.current().unwrap() = 30;
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(Some(&mut 4), cursor.current());
+            cursor.move_next();
+            assert_eq!(None, cursor.current());
+            cursor.insert_after(5);
+            assert_eq!(None, cursor.current());
+        }
+        assert_eq!(vec![1, 30, 35, 4, 5], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_cursor_insert_into_empty() {
+        let mut list = List::new();
+        {
+            let mut cursor = list.cursor_mut();
+            cursor.insert_after(2);
+            cursor.insert_after(1);
+            assert_eq!(None, cursor.current());
+            cursor.move_next();
+            assert_eq!(Some(&mut 1), cursor.current());
+        }
+        assert_eq!(vec![1, 2], list.iter().cloned().collect::<Vec<_>>());
+
+        let mut list = List::new();
+        {
+            let mut cursor = list.cursor_mut();
+            cursor.move_next();
+            assert_eq!(None, cursor.current());
+            cursor.insert_after(1);
+        }
+        assert_eq!(vec![1], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    // Compares only by key so that stability of sorting can be observed via the tag.
+    #[derive(Debug)]
+    struct Keyed(i32, char);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Keyed) -> bool { self.0 == other.0 }
+    }
+    impl Eq for Keyed {}
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Keyed) -> Option<Ordering> { Some(self.cmp(other)) }
+    }
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Keyed) -> Ordering { self.0.cmp(&other.0) }
+    }
+
+    #[test]
+    fn test_sort_stable() {
+        let mut list = List::new()
+            .with(Keyed(1, 'e')).with(Keyed(2, 'd')).with(Keyed(1, 'c'))
+            .with(Keyed(2, 'b')).with(Keyed(1, 'a'));
+        list.sort();
+        assert_eq!(vec![(1, 'a'), (1, 'c'), (1, 'e'), (2, 'b'), (2, 'd')],
+                   list.iter().map(|k| (k.0, k.1)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list = List::new().with(1).with(2).with(3).with(4).with(5);
+        list.sort();
+        assert_eq!(vec![1, 2, 3, 4, 5], list.iter().cloned().collect::<Vec<_>>());
+        list.sort();
+        assert_eq!(vec![1, 2, 3, 4, 5], list.iter().cloned().collect::<Vec<_>>());
+
+        let mut list: List<i32> = List::new();
+        list.sort();
+        assert_eq!(None, list.peek());
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list: List<i32> = List::new();
+        list.dedup();
+        assert_eq!(None, list.peek());
+
+        // Runs are built in reverse since push() prepends.
+        let mut list = List::new().with(3).with(3).with(2).with(1).with(1).with(1);
+        list.dedup();
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+
+        let mut list = List::new().with(3).with(2).with(2).with(2).with(1);
+        list.dedup();
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+
+        let mut list = List::new().with(1).with(2).with(1).with(3);
+        list.dedup();
+        assert_eq!(vec![3, 1, 2, 1], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = List::new().with(6).with(5).with(4).with(3).with(2).with(1);
+        list.retain(|val| val % 2 == 0);
+        assert_eq!(vec![2, 4, 6], list.iter().cloned().collect::<Vec<_>>());
+
+        let mut list = List::new().with(4).with(3).with(1).with(1);
+        list.retain(|val| val % 2 == 0);
+        assert_eq!(vec![4], list.iter().cloned().collect::<Vec<_>>());
+
+        list.retain(|_| true);
+        assert_eq!(vec![4], list.iter().cloned().collect::<Vec<_>>());
+
+        let mut list = List::new().with(3).with(2).with(1);
+        list.retain(|_| true);
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+        list.retain(|_| false);
+        assert_eq!(None, list.peek());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut list = List::new().with(5).with(3).with(1);
+        let mut other = List::new().with(6).with(4).with(2);
+        list.merge(&mut other);
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(None, other.peek());
+
+        let mut list = List::new().with(6).with(5).with(4);
+        let mut other = List::new().with(3).with(2).with(1);
+        list.merge(&mut other);
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(None, other.peek());
+
+        let mut list = List::new().with(Keyed(1, 'a'));
+        let mut other = List::new().with(Keyed(1, 'b'));
+        list.merge(&mut other);
+        assert_eq!(vec!['a', 'b'], list.iter().map(|k| k.1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        let mut list = List::new();
+        let mut other = List::new().with(2).with(1);
+        list.merge(&mut other);
+        assert_eq!(vec![1, 2], list.iter().cloned().collect::<Vec<_>>());
+        list.merge(&mut other);
+        assert_eq!(vec![1, 2], list.iter().cloned().collect::<Vec<_>>());
+
+        let mut list: List<i32> = List::new();
+        list.merge(&mut List::new());
+        assert_eq!(None, list.peek());
+    }
+
+    #[test]
+    fn test_split_after() {
+        let mut list = List::new().with(3).with(2).with(1);
+        let rest = list.split_after(|val| *val == 1).unwrap();
+        assert_eq!(vec![1], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(vec![2, 3], rest.iter().cloned().collect::<Vec<_>>());
+
+        let mut list = List::new().with(3).with(2).with(1);
+        let rest = list.split_after(|val| *val == 2).unwrap();
+        assert_eq!(vec![1, 2], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(vec![3], rest.iter().cloned().collect::<Vec<_>>());
+
+        let mut list = List::new().with(3).with(2).with(1);
+        let rest = list.split_after(|val| *val == 3).unwrap();
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(None, rest.peek());
+
+        let mut list = List::new().with(3).with(2).with(1);
+        assert!(list.split_after(|val| *val == 4).is_none());
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let list = List::new().with(3).with(2).with(1);
+        let json = ::serde_json::to_string(&list).unwrap();
+        assert_eq!("[1,2,3]", json);
+        let list: List<i32> = ::serde_json::from_str(&json).unwrap();
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_eq_hash() {
+        let list1 = List::new().with(3).with(2).with(1);
+        let mut list2 = List::new();
+        list2.push(3).push(2).push(1);
+        assert!(list1 == list2);
+        assert!(list1 != List::new().with(3).with(2));
+        assert!(list1 != List::new().with(4).with(2).with(1));
+
+        let mut set = HashSet::new();
+        set.insert(list1);
+        set.insert(list2);
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let list = List::from_slice(&[1, 2, 3]);
+        assert_eq!(vec![1, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+        let list: List<i32> = List::from_slice(&[]);
+        assert_eq!(None, list.peek());
+    }
+
+    #[test]
+    fn test_filter_map() {
+        let list = List::from_slice(&["1", "two", "3", "", "5"]);
+        let parsed = list.filter_map(|s| s.parse::<i32>().ok());
+        assert_eq!(vec![1, 3, 5], parsed.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Wrapper {
+            list: List<i32>,
+        }
+
+        let mut wrapper = Wrapper::default();
+        assert_eq!(None, wrapper.list.peek());
+        wrapper.list.push(1);
+        assert_eq!(Some(&1), wrapper.list.peek());
+    }
+
+    #[test]
+    fn test_into_sorted_vec() {
+        assert_eq!(vec![1, 2, 3, 4], List::from_slice(&[3, 1, 4, 2]).into_sorted_vec());
+        assert_eq!(vec![1, 2, 3, 4], List::from_slice(&[4, 3, 2, 1]).into_sorted_vec());
+        assert_eq!(vec![1, 2, 3, 4], List::from_slice(&[1, 2, 3, 4]).into_sorted_vec());
+        assert_eq!(Vec::<i32>::new(), List::<i32>::new().into_sorted_vec());
+    }
+
+    #[test]
+    fn test_from_persistent() {
+        let persistent = immutable_linked_list::List::from_slice(&[1, 2, 3]);
+        let mut list = List::from(&persistent);
+        assert_eq!(Some(1), list.pop());
+        list.push(4);
+        assert_eq!(vec![4, 2, 3], list.iter().cloned().collect::<Vec<_>>());
+        // The persistent list is unaffected.
+        assert_eq!(vec![1, 2, 3], persistent.to_vec());
+    }
+
+    #[test]
+    fn test_into_vec() {
+        #[derive(Debug, PartialEq)]
+        struct NoClone(i32);
+
+        let mut list = List::new();
+        list.push(Box::new(NoClone(3))).push(Box::new(NoClone(2))).push(Box::new(NoClone(1)));
+        let vec = list.into_vec();
+        assert_eq!(vec![Box::new(NoClone(1)), Box::new(NoClone(2)), Box::new(NoClone(3))], vec);
+        assert_eq!(Vec::<Box<NoClone>>::new(), List::new().into_vec());
+    }
+
+    #[test]
+    fn test_drop_partial_into_iter() {
+        let mut list = List::new();
+        for i in 0..1_000_000 {
+            list.push(i);
+        }
+        let mut iter = list.into_iter();
+        assert_eq!(Some(999_999), iter.next());
+        assert_eq!(Some(999_998), iter.next());
+        drop(iter);
+    }
 }
@@ -0,0 +1,15 @@
+extern crate lists;
+
+use lists::immutable_linked_list::List;
+use lists::{cons, PersistentList};
+
+#[test]
+fn test_public_api() {
+    let list = cons(3, cons(2, List::new().prepend(1)));
+    assert_eq!(Some(&3), list.head());
+    assert_eq!(Some(&2), list.tail().head());
+    assert_eq!(vec![3, 2, 1], list.iter().cloned().collect::<Vec<_>>());
+
+    let list: PersistentList<_> = PersistentList::cons(4, list);
+    assert_eq!(vec![4, 3, 2, 1], (&list).into_iter().cloned().collect::<Vec<_>>());
+}
@@ -0,0 +1,18 @@
+extern crate lists;
+
+use lists::prelude::*;
+
+#[test]
+fn test_prelude() {
+    let mut doubly = DoublyLinkedList::new();
+    doubly.push_back(2);
+    doubly.push_front(1);
+    assert_eq!(vec![1, 2], doubly.iter().cloned().collect::<Vec<_>>());
+
+    let mut singly = SinglyLinkedList::new();
+    singly.push(2).push(1);
+    assert_eq!(vec![1, 2], singly.iter().cloned().collect::<Vec<_>>());
+
+    let persistent = PersistentList::new().prepend(2).prepend(1);
+    assert_eq!(vec![1, 2], persistent.to_vec());
+}